@@ -0,0 +1,103 @@
+use anyhow::Context;
+
+use crate::{
+  containers::{
+    commands::move_container_within_tree, traits::CommonGetters, Container,
+  },
+  wm_state::WmState,
+};
+
+/// Where to place a container relative to a target container.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Position {
+  Before,
+  After,
+}
+
+/// Resolves the `(parent, index)` a container should land at to sit directly
+/// before or after `target` under `target`'s parent.
+///
+/// Used by the initial insertion heuristic (`insertion_target`). It computes
+/// the slot through `resolve_insert_index`, the same helper the before/after
+/// move operation ([`insert_container`]) uses, so both place containers
+/// relative to a target the same way.
+pub fn insertion_index(
+  target: &Container,
+  position: Position,
+) -> anyhow::Result<(Container, usize)> {
+  let parent = target.parent().context("Target has no parent.")?;
+  Ok((parent, resolve_insert_index(target.index(), position, false)))
+}
+
+/// Computes the slot a container should be inserted at relative to a target.
+///
+/// `removed_before_target` is true when the container being moved currently
+/// shares the target's parent and precedes it: detaching it first shifts the
+/// target's index down by one, so the insert index must be decremented to
+/// avoid landing one slot too far (classic off-by-one).
+pub(crate) fn resolve_insert_index(
+  target_index: usize,
+  position: Position,
+  removed_before_target: bool,
+) -> usize {
+  let base = match position {
+    Position::Before => target_index,
+    Position::After => target_index + 1,
+  };
+
+  if removed_before_target {
+    base.saturating_sub(1)
+  } else {
+    base
+  }
+}
+
+/// Inserts `container` directly before or after `target` under `target`'s
+/// parent, detaching it from its current position first.
+pub fn insert_container(
+  container: Container,
+  target: &Container,
+  position: Position,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let parent = target.parent().context("Target has no parent.")?;
+
+  // If the container currently sits before the target under the same parent,
+  // detaching it shifts the target's index down by one.
+  let removed_before_target = container
+    .parent()
+    .is_some_and(|p| p.id() == parent.id() && container.index() < target.index());
+
+  let index =
+    resolve_insert_index(target.index(), position, removed_before_target);
+
+  move_container_within_tree(container, parent.clone(), index)?;
+  state.add_container_to_redraw(parent);
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{resolve_insert_index, Position};
+
+  #[test]
+  fn inserts_before_and_after_target() {
+    assert_eq!(resolve_insert_index(2, Position::Before, false), 2);
+    assert_eq!(resolve_insert_index(2, Position::After, false), 3);
+  }
+
+  #[test]
+  fn adjusts_for_preceding_sibling_removal() {
+    // Moving a container that precedes the target within the same parent:
+    // the target shifts left by one after detach.
+    assert_eq!(resolve_insert_index(2, Position::Before, true), 1);
+    assert_eq!(resolve_insert_index(2, Position::After, true), 2);
+  }
+
+  #[test]
+  fn before_at_index_zero_does_not_underflow() {
+    assert_eq!(resolve_insert_index(0, Position::Before, true), 0);
+  }
+}
@@ -0,0 +1,19 @@
+mod attach_container;
+mod insert_container;
+mod move_container_within_tree;
+mod persist_layout;
+mod redraw;
+mod replace_container;
+mod run_with_subject_container;
+mod scroll_workspace;
+mod set_focused_descendant;
+
+pub use attach_container::*;
+pub use insert_container::*;
+pub use move_container_within_tree::*;
+pub use persist_layout::*;
+pub use redraw::*;
+pub use replace_container::*;
+pub use run_with_subject_container::*;
+pub use scroll_workspace::*;
+pub use set_focused_descendant::*;
@@ -0,0 +1,388 @@
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  common::{
+    platform::{NativeWindow, Platform},
+    Rect,
+  },
+  containers::{
+    commands::{attach_container, move_container_within_tree},
+    traits::{CommonGetters, PositionGetters, TilingSizeGetters},
+    Container, SplitContainer, WindowContainer,
+  },
+  user_config::UserConfig,
+  windows::{
+    commands::{manage_window, update_window_state},
+    traits::WindowGetters,
+    WindowState,
+  },
+  wm_state::WmState,
+};
+
+/// Serializable snapshot of a container subtree.
+///
+/// Mirrors the live tree closely enough to rebuild the tiling skeleton:
+/// split containers keep their orientation and tiling size, workspaces keep
+/// their name, and windows keep their state plus the native handle and
+/// process/title used to re-match them on restore.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LayoutNode {
+  Workspace {
+    name: String,
+    children: Vec<LayoutNode>,
+  },
+  Split {
+    tiling_direction: String,
+    tiling_size: f32,
+    children: Vec<LayoutNode>,
+  },
+  Window {
+    handle: isize,
+    process_name: Option<String>,
+    title: Option<String>,
+    state: WindowState,
+    tiling_size: f32,
+    floating_placement: RectDto,
+  },
+}
+
+/// Serializable rectangle used for floating placements.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct RectDto {
+  pub x: i32,
+  pub y: i32,
+  pub width: i32,
+  pub height: i32,
+}
+
+/// Snapshots the layout of every workspace into a JSON document.
+///
+/// Walks the live tree top-down, capturing split containers, workspaces and
+/// per-window state so the skeleton can be rebuilt verbatim on restore.
+pub fn save_layout(state: &WmState) -> anyhow::Result<String> {
+  let workspaces = state
+    .workspaces()
+    .into_iter()
+    .map(|workspace| serialize_node(&workspace.into()))
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
+  serde_json::to_string_pretty(&workspaces)
+    .context("Failed to serialize layout.")
+}
+
+fn serialize_node(container: &Container) -> anyhow::Result<LayoutNode> {
+  match container {
+    Container::Workspace(workspace) => Ok(LayoutNode::Workspace {
+      name: workspace.config().name.clone(),
+      children: serialize_children(container)?,
+    }),
+    Container::Split(split) => Ok(LayoutNode::Split {
+      tiling_direction: split.tiling_direction().to_string(),
+      tiling_size: split.tiling_size(),
+      children: serialize_children(container)?,
+    }),
+    Container::TilingWindow(window) => serialize_window(
+      &window.clone().into(),
+      window.tiling_size(),
+    ),
+    Container::NonTilingWindow(window) => {
+      serialize_window(&window.clone().into(), 1.0)
+    }
+    // Monitors and the root aren't part of a workspace's layout.
+    _ => Ok(LayoutNode::Split {
+      tiling_direction: "horizontal".into(),
+      tiling_size: 1.0,
+      children: serialize_children(container)?,
+    }),
+  }
+}
+
+fn serialize_children(
+  container: &Container,
+) -> anyhow::Result<Vec<LayoutNode>> {
+  container
+    .children()
+    .iter()
+    .map(serialize_node)
+    .collect()
+}
+
+fn serialize_window(
+  window: &WindowContainer,
+  tiling_size: f32,
+) -> anyhow::Result<LayoutNode> {
+  let native = window.native();
+  let placement = window.floating_placement();
+
+  Ok(LayoutNode::Window {
+    handle: native.handle(),
+    process_name: native.process_name().ok(),
+    title: native.title().ok(),
+    state: window.state(),
+    tiling_size,
+    floating_placement: RectDto {
+      x: placement.x(),
+      y: placement.y(),
+      width: placement.width(),
+      height: placement.height(),
+    },
+  })
+}
+
+/// Rebuilds the skeleton tree from a previously saved JSON document.
+///
+/// A malformed document, or one with no entries, is rejected before the tree
+/// is touched, so the caller can fall back to live management. Once
+/// reconstruction has started it is neither checked nor undone: an error
+/// part-way (such as an unknown workspace) leaves the containers attached so
+/// far in place, and a restore that matches no live window still succeeds.
+pub fn restore_layout(
+  json: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let nodes: Vec<LayoutNode> =
+    serde_json::from_str(json).context("Malformed layout document.")?;
+
+  if nodes.is_empty() {
+    anyhow::bail!("Restored layout is empty; falling back to live management.");
+  }
+
+  // Resolve saved windows against the live OS window set rather than the
+  // already-managed tree: restore runs at startup before any window has been
+  // slotted into `WmState`, so matching managed containers would find nothing.
+  let native_windows = Platform::manageable_windows().unwrap_or_default();
+
+  for node in &nodes {
+    if let LayoutNode::Workspace { name, children } = node {
+      let workspace = state
+        .workspace_by_name(name)
+        .with_context(|| format!("Unknown workspace '{name}'."))?;
+
+      reattach_children(
+        &workspace.clone().into(),
+        children,
+        &native_windows,
+        state,
+        config,
+      )?;
+    }
+  }
+
+  Ok(())
+}
+
+/// Handles the `save-layout` WM command by snapshotting the current layout to
+/// a named JSON file under the user's layouts directory.
+pub fn save_layout_command(
+  name: &str,
+  state: &WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let path = config.layout_path(name);
+  if let Some(parent) = path.parent() {
+    std::fs::create_dir_all(parent)
+      .context("Failed to create layouts directory.")?;
+  }
+
+  std::fs::write(&path, save_layout(state)?)
+    .with_context(|| format!("Failed to write layout '{name}'."))
+}
+
+/// Handles the `restore-layout` WM command by restoring a named snapshot.
+pub fn restore_layout_command(
+  name: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let json = std::fs::read_to_string(config.layout_path(name))
+    .with_context(|| format!("Failed to read layout '{name}'."))?;
+
+  restore_layout(&json, state, config)
+}
+
+/// Restore hook run once at startup when the first window is managed.
+///
+/// It enumerates the live OS windows itself and manages each into its saved
+/// slot, so it must not assume any window has been slotted into the tree yet.
+///
+/// A failed restore (malformed document, empty document, unknown workspace) is
+/// downgraded to a warning so the WM falls back to live management rather than
+/// aborting startup.
+pub fn restore_on_startup(
+  json: &str,
+  state: &mut WmState,
+  config: &UserConfig,
+) {
+  if let Err(err) = restore_layout(json, state, config) {
+    tracing::warn!("Failed to restore saved layout: {err:#}. Falling back to live management.");
+  }
+}
+
+impl UserConfig {
+  /// Resolves the on-disk path for a named layout snapshot, stored next to the
+  /// user config file under a `layouts/` directory.
+  pub(crate) fn layout_path(&self, name: &str) -> PathBuf {
+    self.dir.join("layouts").join(format!("{name}.json"))
+  }
+}
+
+fn reattach_children(
+  parent: &Container,
+  children: &[LayoutNode],
+  native_windows: &[NativeWindow],
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  // Track the actual slot to attach the next child at. Windows whose native
+  // handle can't be found on restore are skipped, so we can't use the saved
+  // position in `children`; incrementing only on a successful attach keeps the
+  // restored order gap-free.
+  let mut attach_index = 0;
+
+  for node in children {
+    match node {
+      LayoutNode::Split { tiling_direction, tiling_size, children } => {
+        let split = SplitContainer::new(
+          tiling_direction.parse().unwrap_or_default(),
+          config.value.gaps.inner_gap.clone(),
+        );
+        split.set_tiling_size(*tiling_size);
+
+        attach_container(&split.clone().into(), parent, Some(attach_index))?;
+        reattach_children(
+          &split.into(),
+          children,
+          native_windows,
+          state,
+          config,
+        )?;
+        attach_index += 1;
+      }
+      LayoutNode::Window {
+        handle,
+        process_name,
+        title,
+        state: window_state,
+        tiling_size,
+        floating_placement,
+      } => {
+        // Match the saved slot against a live OS window by handle, or by
+        // process/title when the original handle is gone, then manage it
+        // straight into this slot. Windows that are no longer open are
+        // skipped, leaving the restored order gap-free.
+        let Some(native_window) = native_windows
+          .iter()
+          .find(|native| native.handle() == *handle)
+          .or_else(|| {
+            native_windows.iter().find(|native| {
+              native.process_name().ok().as_deref() == process_name.as_deref()
+                && native.title().ok().as_deref() == title.as_deref()
+            })
+          })
+          .cloned()
+        else {
+          continue;
+        };
+
+        manage_window(
+          native_window.clone(),
+          Some(parent.clone()),
+          state,
+          config,
+        )?;
+
+        // `manage_window` may drop the window if a window rule unmanaged it.
+        let Some(window) =
+          state.window_from_native_handle(native_window.handle())
+        else {
+          continue;
+        };
+
+        // Apply the saved state: converting between tiling and floating
+        // relocates the window via the usual insertion heuristic, so re-resolve
+        // the container afterwards since the conversion replaces it.
+        if window.state() != *window_state {
+          update_window_state(
+            window.clone(),
+            window_state.clone(),
+            state,
+            config,
+          )?;
+        }
+
+        let window = state
+          .window_from_native_handle(native_window.handle())
+          .context("Window vanished mid-restore.")?;
+
+        window.set_floating_placement(Rect::from_xy(
+          floating_placement.x,
+          floating_placement.y,
+          floating_placement.width,
+          floating_placement.height,
+        ));
+
+        // Pin the window to its saved slot: `manage_window` attached it at the
+        // front of the parent, so move it into the recorded order.
+        move_container_within_tree(
+          window.clone().into(),
+          parent.clone(),
+          attach_index,
+        )?;
+
+        if let WindowContainer::TilingWindow(tiling_window) = window {
+          tiling_window.set_tiling_size(*tiling_size);
+        }
+
+        attach_index += 1;
+      }
+      // Nested workspaces aren't expected inside a workspace skeleton.
+      LayoutNode::Workspace { .. } => {}
+    }
+  }
+
+  Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{LayoutNode, RectDto};
+  use crate::windows::WindowState;
+
+  fn sample() -> LayoutNode {
+    LayoutNode::Workspace {
+      name: "1".into(),
+      children: vec![LayoutNode::Split {
+        tiling_direction: "horizontal".into(),
+        tiling_size: 1.0,
+        children: vec![LayoutNode::Window {
+          handle: 42,
+          process_name: Some("code.exe".into()),
+          title: Some("editor".into()),
+          state: WindowState::Tiling,
+          tiling_size: 0.5,
+          floating_placement: RectDto {
+            x: 10,
+            y: 20,
+            width: 800,
+            height: 600,
+          },
+        }],
+      }],
+    }
+  }
+
+  #[test]
+  fn layout_survives_a_serde_round_trip() {
+    let json = serde_json::to_string(&sample()).unwrap();
+    let restored: LayoutNode = serde_json::from_str(&json).unwrap();
+
+    // Re-serializing the restored tree reproduces the original document,
+    // confirming every field survives the round trip.
+    assert_eq!(serde_json::to_string(&restored).unwrap(), json);
+  }
+}
@@ -0,0 +1,95 @@
+use uuid::Uuid;
+
+use crate::{
+  containers::{
+    commands::{scroll_to_focused_column, viewport_adjusted_rect},
+    traits::CommonGetters,
+    Container, WindowContainer, WorkspaceContainer,
+  },
+  windows::{commands::hidden_by_global_fullscreen, traits::WindowGetters},
+  wm_state::WmState,
+};
+
+/// Repaints the windows queued for redraw.
+///
+/// Each window is positioned at its laid-out rect after applying its
+/// workspace's scrollable viewport, so paper-mode columns scroll into view
+/// rather than clamping to the workspace. Windows hidden underneath a
+/// globally-fullscreen window are kept off-screen instead of being painted.
+pub fn redraw(state: &mut WmState) -> anyhow::Result<()> {
+  let containers = state.containers_to_redraw();
+
+  let windows: Vec<WindowContainer> =
+    containers.iter().flat_map(windows_within).collect();
+
+  // Recompute the viewport of every scrollable workspace about to be painted
+  // so focusing an already-existing off-strip column scrolls it into view, not
+  // just adding or moving one.
+  for workspace in scrollable_workspaces(&windows, state) {
+    let viewport = scroll_to_focused_column(&workspace)?;
+    state.set_scrollable_viewport(workspace.id(), viewport);
+  }
+
+  for window in windows {
+    // A globally-fullscreen window spans every monitor, so suppress drawing
+    // the contents of the workspaces it covers.
+    if hidden_by_global_fullscreen(&window.clone().into(), state) {
+      window.native().set_visible(false)?;
+      continue;
+    }
+
+    let rect = viewport_adjusted_rect(&window, state)?;
+    window.native().set_visible(true)?;
+    window.native().set_position(&window.state(), &rect)?;
+  }
+
+  state.clear_containers_to_redraw();
+
+  Ok(())
+}
+
+/// The distinct scrollable workspaces the given windows belong to.
+///
+/// A workspace is treated as scrollable when it already has a stored viewport,
+/// which is set when its first column is created in paper mode.
+fn scrollable_workspaces(
+  windows: &[WindowContainer],
+  state: &WmState,
+) -> Vec<WorkspaceContainer> {
+  let mut seen: Vec<Uuid> = Vec::new();
+  let mut workspaces = Vec::new();
+
+  for window in windows {
+    let Some(workspace) = window.parent_workspace() else {
+      continue;
+    };
+
+    if seen.contains(&workspace.id()) {
+      continue;
+    }
+    seen.push(workspace.id());
+
+    if state.scrollable_viewport(workspace.id()).is_some() {
+      workspaces.push(workspace);
+    }
+  }
+
+  workspaces
+}
+
+/// Flattens a queued container into the windows to repaint beneath it.
+fn windows_within(container: &Container) -> Vec<WindowContainer> {
+  container
+    .self_and_descendants()
+    .into_iter()
+    .filter_map(|container| match container {
+      Container::TilingWindow(window) => {
+        Some(WindowContainer::TilingWindow(window))
+      }
+      Container::NonTilingWindow(window) => {
+        Some(WindowContainer::NonTilingWindow(window))
+      }
+      _ => None,
+    })
+    .collect()
+}
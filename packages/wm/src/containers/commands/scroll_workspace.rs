@@ -0,0 +1,237 @@
+use anyhow::Context;
+use uuid::Uuid;
+
+use crate::{
+  common::Rect,
+  containers::{
+    commands::move_container_within_tree,
+    traits::{CommonGetters, PositionGetters, TilingSizeGetters},
+    Container, WindowContainer, WorkspaceContainer,
+  },
+  wm_state::WmState,
+};
+
+/// Fraction of the workspace width a single column occupies in scrollable
+/// ("paper") mode. Columns have this intrinsic width regardless of how many
+/// exist, so the strip overflows the workspace and must be scrolled.
+pub const COLUMN_WIDTH_FRACTION: f32 = 0.5;
+
+/// Direction to move the scrollable-tiling viewport or a column.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScrollDirection {
+  Left,
+  Right,
+}
+
+/// Recomputes a scrollable workspace's viewport and queues a redraw.
+///
+/// Call this after a column is added or moved. Plain focus changes don't go
+/// through here: the redraw pass recomputes the viewport before painting, so a
+/// freshly focused existing column still scrolls into view. The computed
+/// viewport is stored on [`WmState`] for the redraw pass to translate column
+/// positions by.
+pub fn apply_scrollable_viewport(
+  workspace: &WorkspaceContainer,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let viewport = scroll_to_focused_column(workspace)?;
+  state.set_scrollable_viewport(workspace.id(), viewport);
+  state.add_container_to_redraw(workspace.clone().into());
+  Ok(())
+}
+
+/// Computes the viewport rect (the horizontal window into the infinite strip)
+/// that brings the focused column fully into view.
+///
+/// Column positions are derived from each column's index and intrinsic width
+/// rather than its live laid-out rect, since the tiling engine clamps laid-out
+/// rects to the workspace: indexing is what makes the strip "infinite".
+pub fn scroll_to_focused_column(
+  workspace: &WorkspaceContainer,
+) -> anyhow::Result<Rect> {
+  let workspace_rect = workspace.to_rect()?;
+  let column_width = column_width(workspace_rect.width());
+
+  let focused_index = focused_column_index(workspace);
+  let offset_x = viewport_offset(
+    column_width,
+    focused_index,
+    workspace_rect.width(),
+    workspace.child_count(),
+  );
+
+  Ok(Rect::from_xy(
+    workspace_rect.x() + offset_x,
+    workspace_rect.y(),
+    workspace_rect.width(),
+    workspace_rect.height(),
+  ))
+}
+
+/// Moves the focused column one slot left or right within the strip.
+pub fn move_column(
+  direction: ScrollDirection,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let focused = state.focused_container().context("No focused container.")?;
+  let workspace =
+    focused.parent_workspace().context("No parent workspace.")?;
+  let column =
+    column_of(&focused, &workspace).context("No focused column.")?;
+
+  let target_index = match direction {
+    ScrollDirection::Left => column.index().saturating_sub(1),
+    ScrollDirection::Right => {
+      (column.index() + 1).min(workspace.child_count().saturating_sub(1))
+    }
+  };
+
+  move_container_within_tree(column, workspace.clone().into(), target_index)?;
+
+  // Keep the moved column within the viewport after the reorder.
+  apply_scrollable_viewport(&workspace, state)?;
+  Ok(())
+}
+
+/// A window's on-screen rect with its workspace's scrollable viewport applied.
+///
+/// For a window in a scrollable ("paper") workspace the horizontal placement is
+/// derived from its column's index and the intrinsic [`column_width`], not from
+/// the tiling engine's laid-out rect: glazewm normalizes sibling tiling sizes
+/// to sum to 1.0, which would shrink columns to fit the workspace and defeat
+/// the overflowing strip. This keeps the draw path on the same coordinate
+/// system as [`scroll_to_focused_column`] so columns keep a fixed width and the
+/// focused one scrolls into view without spilling onto an adjacent monitor. The
+/// vertical placement (stacking within a column) still comes from the laid-out
+/// rect. Windows outside a scrollable workspace are returned unchanged; the
+/// redraw path calls this for every window it repaints.
+pub fn viewport_adjusted_rect(
+  window: &WindowContainer,
+  state: &WmState,
+) -> anyhow::Result<Rect> {
+  let rect = window.to_rect()?;
+
+  let Some(workspace) = window.parent_workspace() else {
+    return Ok(rect);
+  };
+
+  let Some(viewport) = state.scrollable_viewport(workspace.id()) else {
+    return Ok(rect);
+  };
+
+  let Some(column) = column_of(&window.clone().into(), &workspace) else {
+    return Ok(rect);
+  };
+
+  let workspace_rect = workspace.to_rect()?;
+  let column_width = column_width(workspace_rect.width());
+
+  // The viewport's x is the workspace origin shifted right by the scroll
+  // offset, so placing the column at `index * column_width` minus that offset
+  // scrolls the whole strip.
+  let offset_x = viewport.x() - workspace_rect.x();
+  let x =
+    workspace_rect.x() + column.index() as i32 * column_width - offset_x;
+
+  Ok(Rect::from_xy(x, rect.y(), column_width, rect.height()))
+}
+
+/// The intrinsic pixel width of a column given the workspace width.
+pub(crate) fn column_width(workspace_width: i32) -> i32 {
+  (workspace_width as f32 * COLUMN_WIDTH_FRACTION) as i32
+}
+
+/// Horizontal offset, in pixels, to apply to the strip so the column at
+/// `focused_index` is fully visible within a `workspace_width`-wide viewport.
+///
+/// Returns 0 when the focused column already fits at the current left-aligned
+/// position; otherwise shifts just enough to pull the column's right edge to
+/// the workspace's right edge.
+pub(crate) fn viewport_offset(
+  column_width: i32,
+  focused_index: usize,
+  workspace_width: i32,
+  column_count: usize,
+) -> i32 {
+  if column_count == 0 || column_width <= 0 {
+    return 0;
+  }
+
+  let focused_left = focused_index as i32 * column_width;
+  let focused_right = focused_left + column_width;
+
+  if focused_right > workspace_width {
+    focused_right - workspace_width
+  } else {
+    0
+  }
+}
+
+/// Index of the focused top-level column within the workspace, if any.
+fn focused_column_index(workspace: &WorkspaceContainer) -> usize {
+  workspace
+    .descendant_focus_order()
+    .find_map(|c| column_of(&c, workspace))
+    .map(|column| column.index())
+    .unwrap_or(0)
+}
+
+/// Resolves the top-level column a container belongs to within `workspace`.
+pub(crate) fn column_of(
+  container: &Container,
+  workspace: &WorkspaceContainer,
+) -> Option<Container> {
+  let mut current = container.clone();
+  loop {
+    let parent = current.parent()?;
+    if parent.id() == workspace.id() {
+      return Some(current);
+    }
+    current = parent;
+  }
+}
+
+impl WmState {
+  /// Records the viewport rect of a scrollable workspace so the redraw pass
+  /// can translate that workspace's column positions by it.
+  pub fn set_scrollable_viewport(&mut self, workspace_id: Uuid, rect: Rect) {
+    self.scrollable_viewports.insert(workspace_id, rect);
+  }
+
+  /// Returns the stored viewport of a scrollable workspace, if one has been
+  /// computed. Consulted by the redraw pass for paper-layout workspaces.
+  pub fn scrollable_viewport(&self, workspace_id: Uuid) -> Option<Rect> {
+    self.scrollable_viewports.get(&workspace_id).copied()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use super::{column_width, viewport_offset};
+
+  #[test]
+  fn column_has_intrinsic_half_width() {
+    assert_eq!(column_width(1000), 500);
+  }
+
+  #[test]
+  fn focused_column_in_first_screen_needs_no_offset() {
+    // Two 500px columns exactly fill a 1000px workspace.
+    assert_eq!(viewport_offset(500, 0, 1000, 3), 0);
+    assert_eq!(viewport_offset(500, 1, 1000, 3), 0);
+  }
+
+  #[test]
+  fn offscreen_focused_column_scrolls_right_edge_into_view() {
+    // Column 2 spans 1000..1500 in a 1000-wide viewport, so scroll by 500.
+    assert_eq!(viewport_offset(500, 2, 1000, 3), 500);
+    assert_eq!(viewport_offset(500, 3, 1000, 4), 1000);
+  }
+
+  #[test]
+  fn degenerate_inputs_do_not_scroll() {
+    assert_eq!(viewport_offset(0, 5, 1000, 3), 0);
+    assert_eq!(viewport_offset(500, 0, 1000, 0), 0);
+  }
+}
@@ -0,0 +1,115 @@
+use serde::Deserialize;
+
+use crate::{
+  containers::{
+    commands::{
+      insert_container, move_column, restore_layout_command,
+      save_layout_command, Position, ScrollDirection,
+    },
+    traits::CommonGetters,
+    Container,
+  },
+  user_config::UserConfig,
+  windows::commands::{
+    enforce_workspace_rules, toggle_global_fullscreen, unmanage_window,
+  },
+  wm_state::WmState,
+};
+
+/// A WM command that can be dispatched against a subject container.
+///
+/// Commands are parsed from keybindings and window rules, then run via
+/// [`InvokeCommand::run`] with the subject container (the focused container, or
+/// the container a window rule matched).
+#[derive(Clone, Debug, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum InvokeCommand {
+  /// Move the focused column left or right within a scrollable workspace.
+  MoveColumn { direction: ScrollDirection },
+  /// Move the subject container before/after its adjacent sibling.
+  MoveWindow { position: Position },
+  /// Toggle the subject window into or out of global (all-monitor) fullscreen.
+  ToggleGlobalFullscreen,
+  /// Snapshot the current layout to a named file under the layouts directory.
+  SaveLayout { name: String },
+  /// Restore a named layout snapshot over the live tree.
+  RestoreLayout { name: String },
+  /// Re-apply workspace-assignment rules to the subject window, honoring
+  /// `initial_only` so only non-initial rules can move it.
+  ApplyWorkspaceRules,
+  /// Stop managing the subject window, detaching it from the tree. Typically
+  /// run from a window rule to ignore a window the WM should never tile.
+  Unmanage,
+}
+
+impl InvokeCommand {
+  /// Dispatches the command, running it as if `subject` were focused.
+  pub fn run(
+    &self,
+    subject: Container,
+    state: &mut WmState,
+    config: &UserConfig,
+  ) -> anyhow::Result<()> {
+    match self {
+      InvokeCommand::MoveColumn { direction } => move_column(*direction, state),
+      InvokeCommand::MoveWindow { position } => {
+        // Place the subject relative to its neighbor in that direction.
+        let neighbor = match position {
+          Position::Before => subject.prev_siblings().next(),
+          Position::After => subject.next_siblings().next(),
+        };
+
+        if let Some(neighbor) = neighbor {
+          insert_container(subject, &neighbor, *position, state)?;
+        }
+
+        Ok(())
+      }
+      InvokeCommand::ToggleGlobalFullscreen => {
+        let window = match subject {
+          Container::TilingWindow(window) => Some(window.into()),
+          Container::NonTilingWindow(window) => Some(window.into()),
+          _ => None,
+        };
+
+        if let Some(window) = window {
+          toggle_global_fullscreen(window, state, config)?;
+        }
+
+        Ok(())
+      }
+      InvokeCommand::SaveLayout { name } => {
+        save_layout_command(name, state, config)
+      }
+      InvokeCommand::RestoreLayout { name } => {
+        restore_layout_command(name, state, config)
+      }
+      InvokeCommand::ApplyWorkspaceRules => {
+        let window = match subject {
+          Container::TilingWindow(window) => Some(window.into()),
+          Container::NonTilingWindow(window) => Some(window.into()),
+          _ => None,
+        };
+
+        if let Some(window) = window {
+          enforce_workspace_rules(window, state, config)?;
+        }
+
+        Ok(())
+      }
+      InvokeCommand::Unmanage => {
+        let window = match subject {
+          Container::TilingWindow(window) => Some(window.into()),
+          Container::NonTilingWindow(window) => Some(window.into()),
+          _ => None,
+        };
+
+        if let Some(window) = window {
+          unmanage_window(window, state)?;
+        }
+
+        Ok(())
+      }
+    }
+  }
+}
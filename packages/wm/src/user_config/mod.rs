@@ -0,0 +1,13 @@
+mod invoke_command;
+mod window_rule;
+mod workspace_layout;
+mod workspace_rule;
+
+pub use invoke_command::*;
+pub use window_rule::*;
+pub use workspace_layout::*;
+pub use workspace_rule::*;
+
+// The core `UserConfig`/`ParsedConfig` types and their `value` field live
+// alongside these submodules in this module; the feature-specific rule and
+// layout types above extend them via their own `impl` blocks.
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use regex::Regex;
+use serde::{Deserialize, Deserializer};
+
+use crate::{
+  containers::WindowContainer,
+  user_config::{InvokeCommand, UserConfig},
+  windows::traits::WindowGetters,
+};
+
+/// A regex-backed pattern used to match a window's native properties.
+///
+/// Patterns are case-insensitive and anchored as written by the user; an
+/// invalid pattern fails config parsing rather than silently never matching.
+#[derive(Clone, Debug)]
+pub struct WindowMatchPattern(Regex);
+
+impl WindowMatchPattern {
+  pub(crate) fn is_match(&self, value: &str) -> bool {
+    self.0.is_match(value)
+  }
+}
+
+/// Matches the process name, title and class-name matchers against a window's
+/// corresponding properties, treating any unspecified matcher as a wildcard.
+///
+/// Shared by [`WindowRuleConfig`] and
+/// [`WorkspaceRuleConfig`](super::WorkspaceRuleConfig), which carry the same
+/// three matcher fields.
+pub(crate) fn matches_window(
+  match_process_name: &Option<WindowMatchPattern>,
+  match_title: &Option<WindowMatchPattern>,
+  match_class_name: &Option<WindowMatchPattern>,
+  process: &str,
+  title: &str,
+  class: &str,
+) -> bool {
+  let matches = |pattern: &Option<WindowMatchPattern>, value: &str| {
+    pattern.as_ref().map_or(true, |p| p.is_match(value))
+  };
+
+  matches(match_process_name, process)
+    && matches(match_title, title)
+    && matches(match_class_name, class)
+}
+
+impl<'de> Deserialize<'de> for WindowMatchPattern {
+  fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+  where
+    D: Deserializer<'de>,
+  {
+    let pattern = String::deserialize(deserializer)?;
+    Regex::from_str(&format!("(?i){pattern}"))
+      .map(WindowMatchPattern)
+      .map_err(serde::de::Error::custom)
+  }
+}
+
+/// A user-defined window rule: a set of matchers plus the WM commands to run
+/// against any window they match.
+///
+/// A rule matches when every specified matcher matches; an unspecified matcher
+/// is treated as a wildcard.
+#[derive(Clone, Debug, Deserialize)]
+pub struct WindowRuleConfig {
+  #[serde(default)]
+  pub match_process_name: Option<WindowMatchPattern>,
+  #[serde(default)]
+  pub match_title: Option<WindowMatchPattern>,
+  #[serde(default)]
+  pub match_class_name: Option<WindowMatchPattern>,
+  pub commands: Vec<InvokeCommand>,
+}
+
+impl WindowRuleConfig {
+  fn is_match(&self, process: &str, title: &str, class: &str) -> bool {
+    matches_window(
+      &self.match_process_name,
+      &self.match_title,
+      &self.match_class_name,
+      process,
+      title,
+      class,
+    )
+  }
+}
+
+impl UserConfig {
+  /// Returns the window rules whose matchers match the given window's native
+  /// properties, in config order.
+  pub fn matching_window_rules(
+    &self,
+    window: &WindowContainer,
+  ) -> Vec<&WindowRuleConfig> {
+    let native = window.native();
+    let process = native.process_name().unwrap_or_default();
+    let title = native.title().unwrap_or_default();
+    let class = native.class_name().unwrap_or_default();
+
+    self
+      .value
+      .window_rules
+      .iter()
+      .filter(|rule| rule.is_match(&process, &title, &class))
+      .collect()
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::str::FromStr;
+
+  use regex::Regex;
+
+  use super::WindowMatchPattern;
+
+  fn pattern(raw: &str) -> WindowMatchPattern {
+    WindowMatchPattern(Regex::from_str(&format!("(?i){raw}")).unwrap())
+  }
+
+  #[test]
+  fn matching_is_case_insensitive() {
+    let pattern = pattern("chrome");
+    assert!(pattern.is_match("Chrome"));
+    assert!(pattern.is_match("CHROME.exe"));
+    assert!(!pattern.is_match("firefox"));
+  }
+
+  #[test]
+  fn matching_honors_regex_anchors() {
+    let pattern = pattern("^notepad$");
+    assert!(pattern.is_match("notepad"));
+    assert!(!pattern.is_match("notepad++"));
+  }
+}
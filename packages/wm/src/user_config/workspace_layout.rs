@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+  common::TilingDirection,
+  containers::{traits::CommonGetters, SplitContainer, WorkspaceContainer},
+  user_config::UserConfig,
+};
+
+/// The default arrangement a workspace applies to newly attached windows.
+///
+/// `SplitH`/`SplitV` tile side by side or stacked vertically in the nested
+/// split tree; `Tabbed`/`Stacked` wrap windows in an intermediate container so
+/// they layer rather than tile.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkspaceLayout {
+  #[default]
+  #[serde(rename = "splith")]
+  SplitH,
+  #[serde(rename = "splitv")]
+  SplitV,
+  Tabbed,
+  Stacked,
+  /// Scrollable ("paper") tiling: an infinite horizontal strip of columns.
+  Scrollable,
+}
+
+impl WorkspaceLayout {
+  /// The tiling direction a split container for this layout should use.
+  pub fn tiling_direction(self) -> TilingDirection {
+    match self {
+      WorkspaceLayout::SplitV | WorkspaceLayout::Stacked => {
+        TilingDirection::Vertical
+      }
+      _ => TilingDirection::Horizontal,
+    }
+  }
+}
+
+impl UserConfig {
+  /// Returns the configuration for the given workspace, falling back to the
+  /// default workspace config when the workspace isn't named in the config.
+  pub fn workspace_config(
+    &self,
+    workspace: &WorkspaceContainer,
+  ) -> &WorkspaceConfigValue {
+    let name = workspace.config().name.clone();
+
+    self
+      .value
+      .workspaces
+      .iter()
+      .find(|c| c.name == name)
+      .unwrap_or(&self.value.workspace_defaults)
+  }
+}
+
+/// Per-workspace configuration block parsed from the user config.
+#[derive(Clone, Debug, Default, Deserialize)]
+pub struct WorkspaceConfigValue {
+  pub name: String,
+  #[serde(default)]
+  pub layout: WorkspaceLayout,
+}
+
+impl SplitContainer {
+  /// Applies a workspace layout to this split container, setting its tiling
+  /// direction and tab/stack mode accordingly.
+  pub fn set_layout(&self, layout: WorkspaceLayout) {
+    self.set_tiling_direction(layout.tiling_direction());
+    self.set_tabbed(matches!(
+      layout,
+      WorkspaceLayout::Tabbed | WorkspaceLayout::Stacked
+    ));
+  }
+}
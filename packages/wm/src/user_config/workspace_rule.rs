@@ -0,0 +1,65 @@
+use serde::Deserialize;
+
+use crate::{
+  common::platform::NativeWindow,
+  user_config::{matches_window, UserConfig, WindowMatchPattern},
+};
+
+/// A user-defined workspace-assignment rule.
+///
+/// Matches like a [`WindowRuleConfig`](super::WindowRuleConfig), but instead of
+/// running commands it redirects a newly managed window to `target_workspace`.
+/// When `initial_only` is set the rule fires only the first time a window is
+/// managed and does not keep yanking it back if the user later moves it.
+#[derive(Clone, Debug, Deserialize)]
+pub struct WorkspaceRuleConfig {
+  #[serde(default)]
+  pub match_process_name: Option<WindowMatchPattern>,
+  #[serde(default)]
+  pub match_title: Option<WindowMatchPattern>,
+  #[serde(default)]
+  pub match_class_name: Option<WindowMatchPattern>,
+  pub target_workspace: String,
+  #[serde(default = "default_initial_only")]
+  pub initial_only: bool,
+}
+
+fn default_initial_only() -> bool {
+  true
+}
+
+impl WorkspaceRuleConfig {
+  fn is_match(&self, native: &NativeWindow) -> bool {
+    let process = native.process_name().unwrap_or_default();
+    let title = native.title().unwrap_or_default();
+    let class = native.class_name().unwrap_or_default();
+
+    matches_window(
+      &self.match_process_name,
+      &self.match_title,
+      &self.match_class_name,
+      &process,
+      &title,
+      &class,
+    )
+  }
+}
+
+impl UserConfig {
+  /// Returns the first workspace-assignment rule matching the given window.
+  ///
+  /// `is_initial` is true only on a window's first manage; rules flagged
+  /// `initial_only` are skipped for any later re-evaluation so they don't
+  /// yank a manually moved window back to its assigned workspace.
+  pub fn matching_workspace_rule(
+    &self,
+    native: &NativeWindow,
+    is_initial: bool,
+  ) -> Option<&WorkspaceRuleConfig> {
+    self
+      .value
+      .workspace_rules
+      .iter()
+      .find(|rule| (is_initial || !rule.initial_only) && rule.is_match(native))
+  }
+}
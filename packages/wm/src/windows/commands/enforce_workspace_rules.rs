@@ -0,0 +1,56 @@
+use crate::{
+  containers::{
+    commands::move_container_within_tree,
+    traits::{CommonGetters, PositionGetters},
+    WindowContainer,
+  },
+  user_config::UserConfig,
+  windows::traits::WindowGetters,
+  wm_state::WmState,
+};
+
+/// Re-evaluates workspace-assignment rules for an already-managed window.
+///
+/// Unlike the initial assignment in `manage_window`, this runs with
+/// `is_initial = false`, so only rules whose `initial_only` is `false` can move
+/// the window. This is what gives `initial_only` observable effect: a rule left
+/// at the default fires once and never yanks a manually moved window back,
+/// while a rule with `initial_only = false` keeps the window pinned to its
+/// target workspace on every re-evaluation.
+pub fn enforce_workspace_rules(
+  window: WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  let native = window.native();
+
+  let Some(target) = config
+    .matching_workspace_rule(&native, false)
+    .and_then(|rule| state.workspace_by_name(&rule.target_workspace))
+  else {
+    return Ok(());
+  };
+
+  // Already on the assigned workspace; nothing to do.
+  if window.parent_workspace().map(|w| w.id()) == Some(target.id()) {
+    return Ok(());
+  }
+
+  let index = target.child_count();
+  move_container_within_tree(window.clone().into(), target.clone().into(), index)?;
+
+  // A relocated floating (or otherwise non-tiling) window carries placement
+  // coordinates from its previous monitor, which would leave it off-screen on
+  // the target workspace. Recenter it onto the new workspace, mirroring the
+  // initial float assignment in `create_window`.
+  if let WindowContainer::NonTilingWindow(_) = window {
+    let target_rect = target.to_rect()?;
+    let recentered =
+      window.floating_placement().translate_to_center(&target_rect);
+    window.set_floating_placement(recentered);
+  }
+
+  state.add_container_to_redraw(target.into());
+
+  Ok(())
+}
@@ -4,11 +4,19 @@ use tracing::info;
 use crate::{
   common::platform::NativeWindow,
   containers::{
-    commands::{attach_container, set_focused_descendant},
-    traits::{CommonGetters, PositionGetters},
-    Container, WindowContainer,
+    commands::{
+      apply_scrollable_viewport, attach_container, column_of,
+      insertion_index, restore_on_startup, run_with_subject_container,
+      set_focused_descendant, Position, COLUMN_WIDTH_FRACTION,
+    },
+    traits::{
+      CommonGetters, PositionGetters, TilingDirectionGetters,
+      TilingSizeGetters,
+    },
+    Container, SplitContainer, WindowContainer, WorkspaceContainer,
   },
-  user_config::UserConfig,
+  common::TilingDirection,
+  user_config::{UserConfig, WorkspaceLayout},
   windows::{
     traits::WindowGetters, NonTilingWindow, TilingWindow, WindowState,
   },
@@ -22,25 +30,58 @@ pub fn manage_window(
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<()> {
+  // On the first managed window after startup, replay a saved layout (if any)
+  // so discovered windows land in their persisted slots rather than being
+  // freshly tiled. A failed restore downgrades to live management.
+  if !state.layout_restore_attempted {
+    state.layout_restore_attempted = true;
+    if let Ok(json) = std::fs::read_to_string(config.layout_path("startup")) {
+      restore_on_startup(&json, state, config);
+    }
+
+    // Restore enumerates and manages the open windows itself, so it may have
+    // already slotted this very window into its saved position. Don't manage
+    // it a second time if so.
+    if state
+      .window_from_native_handle(native_window.handle())
+      .is_some()
+    {
+      return Ok(());
+    }
+  }
+
   // Create the window instance.
   let window = create_window(native_window, target_parent, state, config)?;
 
-  // let window_rules = config.matching_window_rules(&window);
-  // let window_rule_commands =
-  //   window_rules.iter().flat_map(|rule| &rule.commands);
+  let window_rules = config.matching_window_rules(&window);
+  let window_rule_commands = window_rules
+    .iter()
+    .flat_map(|rule| rule.commands.clone())
+    .collect::<Vec<_>>();
+
+  // Keep the window's native handle so it can be re-resolved after running
+  // the window rules, in case a rule detaches the window.
+  let window_handle = window.native().handle();
 
   // Set the newly added window as focus descendant. This means the window
   // rules will be run as if the window is focused.
   set_focused_descendant(window.clone().into(), None);
-  // run_with_subject_container(window_rule_commands, window.clone());
+  run_with_subject_container(
+    window_rule_commands,
+    window.clone().into(),
+    state,
+    config,
+  )?;
 
-  // // Update window in case the reference changes.
-  // let window = window_service.get_window_by_handle(window.handle());
+  // Update window in case the reference changes.
+  let window = state.window_from_native_handle(window_handle);
 
-  // // Window might be detached if 'ignore' command has been invoked.
-  // if window.is_none() || !window.unwrap().is_detached() {
-  //   return Ok(());
-  // }
+  // Window might be detached if an 'ignore'/'unmanage' command has been
+  // invoked by one of the window rules.
+  let window = match window {
+    Some(window) if !window.is_detached() => window,
+    _ => return Ok(()),
+  };
 
   // TODO: Log window details.
   info!("New window managed");
@@ -70,12 +111,37 @@ fn create_window(
   state: &mut WmState,
   config: &UserConfig,
 ) -> anyhow::Result<WindowContainer> {
+  let window_state = window_state_to_create(&native_window, config);
+
+  // An explicit target parent means "attach exactly here" (e.g. layout
+  // restore slotting a window into a saved split), so the default-layout
+  // heuristics below are skipped for it — they'd otherwise fabricate an extra
+  // column/wrapper around the restored window.
+  let has_explicit_target = target_parent.is_some();
+
+  // Record whether the window starts out floating (or otherwise non-tiling)
+  // so that status survives a relocation to an assigned workspace instead of
+  // being forced into that workspace's tiling tree.
+  let was_non_tiling = window_state != WindowState::Tiling;
+
+  // A workspace-assignment rule can redirect the window to a configured
+  // target workspace on first manage instead of landing it beside the
+  // focused container. `create_window` only runs on first manage, so the
+  // rule is evaluated with `is_initial = true`.
+  let assigned_workspace = config
+    .matching_workspace_rule(&native_window, true)
+    .and_then(|rule| state.workspace_by_name(&rule.target_workspace));
+  let is_assigned = assigned_workspace.is_some();
+
   // Attach the new window as the first child of the target parent (if
-  // provided), otherwise, add as a sibling of the focused container.
-  let (target_parent, target_index) = match target_parent {
-    Some(parent) => (parent, 0),
-    None => insertion_target(state)?,
-  };
+  // provided), otherwise, redirect it to an assigned workspace, otherwise
+  // add it as a sibling of the focused container.
+  let (target_parent, target_index) =
+    match (target_parent, assigned_workspace) {
+      (Some(parent), _) => (parent, 0),
+      (None, Some(workspace)) => (workspace.into(), 0),
+      (None, None) => insertion_target(state)?,
+    };
 
   let target_workspace = target_parent
     .parent_workspace()
@@ -103,8 +169,6 @@ fn create_window(
       .translate_to_center(&target_workspace.to_rect()?)
   };
 
-  let window_state = window_state_to_create(&native_window, config);
-
   let window_container: WindowContainer = match window_state {
     WindowState::Tiling => TilingWindow::new(
       None,
@@ -124,12 +188,50 @@ fn create_window(
     .into(),
   };
 
+  // When the first real window is attached to an otherwise-empty workspace,
+  // honor that workspace's configured default layout: tabbed/stacked layouts
+  // need an intermediate split container so subsequent windows tab/stack
+  // rather than tiling side by side.
+  let (attach_parent, attach_index) = if was_non_tiling || has_explicit_target
+  {
+    (target_parent, target_index)
+  } else {
+    let workspace = target_parent
+      .parent_workspace()
+      .context("No target workspace.")?;
+
+    // In scrollable ("paper") mode a new tiling window becomes a fresh
+    // column to the right of the focused column rather than a sibling in
+    // the nested split tree.
+    if config.workspace_config(&workspace).layout
+      == WorkspaceLayout::Scrollable
+    {
+      new_column_target(&workspace, state, config)?
+    } else {
+      wrap_in_default_layout(&target_parent, target_index, state, config)?
+    }
+  };
+
   attach_container(
     &window_container.clone().into(),
-    &target_parent,
-    Some(target_index),
+    &attach_parent,
+    Some(attach_index),
   )?;
 
+  // Re-apply the recorded floating placement relative to the assigned
+  // workspace so a relocated floating window keeps its floating layout
+  // instead of snapping into the target workspace's tiling tree.
+  if was_non_tiling && is_assigned {
+    let target_rect = attach_parent
+      .parent_workspace()
+      .context("No target workspace.")?
+      .to_rect()?;
+
+    let recentered =
+      window_container.floating_placement().translate_to_center(&target_rect);
+    window_container.set_floating_placement(recentered);
+  }
+
   // The OS might spawn the window on a different monitor to the target
   // parent, so adjustments might need to be made because of DPI.
   if nearest_monitor
@@ -141,6 +243,104 @@ fn create_window(
   Ok(window_container)
 }
 
+/// Resolves where a new tiling window should attach given the target
+/// workspace's configured default layout.
+///
+/// For a `splith`/`splitv` layout the window attaches directly under the
+/// workspace; its tiling direction is applied to the workspace so the first
+/// window splits along the configured axis. For a `tabbed`/`stacked` layout on
+/// an otherwise-empty workspace, an intermediate split container of that layout
+/// is created and returned as the new attach target so later windows tab/stack
+/// inside it.
+fn wrap_in_default_layout(
+  target_parent: &Container,
+  target_index: usize,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<(Container, usize)> {
+  let Container::Workspace(workspace) = target_parent else {
+    return Ok((target_parent.clone(), target_index));
+  };
+
+  if workspace.child_count() > 0 {
+    return Ok((target_parent.clone(), target_index));
+  }
+
+  let layout = config.workspace_config(&workspace).layout;
+
+  let wrapper = match layout {
+    WorkspaceLayout::Tabbed | WorkspaceLayout::Stacked => {
+      let split = SplitContainer::new(
+        TilingDirection::Horizontal,
+        config.value.gaps.inner_gap.clone(),
+      );
+      split.set_layout(layout);
+      split
+    }
+    // `splith`/`splitv` don't need a wrapper: the window attaches directly
+    // under the workspace, so drive the split by applying the layout's tiling
+    // direction to the workspace itself.
+    WorkspaceLayout::SplitH | WorkspaceLayout::SplitV => {
+      workspace.set_tiling_direction(layout.tiling_direction());
+      return Ok((target_parent.clone(), target_index));
+    }
+    // Scrollable windows are routed to a column before this helper is reached.
+    WorkspaceLayout::Scrollable => {
+      return Ok((target_parent.clone(), target_index))
+    }
+  };
+
+  attach_container(&wrapper.clone().into(), target_parent, Some(target_index))?;
+  state.add_container_to_redraw(wrapper.clone().into());
+
+  Ok((wrapper.into(), 0))
+}
+
+/// Creates a new full-height column to the right of the focused column in a
+/// scrollable-tiling workspace and returns it as the attach target.
+///
+/// Columns are vertical splits laid left-to-right directly under the
+/// workspace; the new column is inserted just after the focused column so the
+/// viewport can scroll it into view on the next redraw.
+fn new_column_target(
+  workspace: &WorkspaceContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<(Container, usize)> {
+  // Resolve the focused column as the direct child of the workspace the
+  // focused descendant lives under (matching `column_of`), not an arbitrary
+  // nested ancestor.
+  let focused_column = workspace
+    .descendant_focus_order()
+    .find_map(|c| column_of(&c, workspace));
+
+  let column_index = focused_column
+    .map(|column| column.index() + 1)
+    .unwrap_or_else(|| workspace.child_count());
+
+  let column = SplitContainer::new(
+    TilingDirection::Vertical,
+    config.value.gaps.inner_gap.clone(),
+  );
+
+  // Give the column the intrinsic width fraction as its tiling size. Its
+  // on-screen position and width come from `viewport_adjusted_rect`, which
+  // derives them from the column index and `COLUMN_WIDTH_FRACTION`, so the
+  // strip overflows the workspace and can be scrolled.
+  column.set_tiling_size(COLUMN_WIDTH_FRACTION);
+
+  attach_container(
+    &column.clone().into(),
+    &workspace.clone().into(),
+    Some(column_index),
+  )?;
+
+  // Scroll the strip so the freshly added column is visible.
+  apply_scrollable_viewport(workspace, state)?;
+
+  Ok((column.into(), 0))
+}
+
 /// Gets the initial state for a window based on its native state.
 ///
 /// Note that maximized windows are initialized as tiling.
@@ -153,6 +353,10 @@ fn window_state_to_create(
   }
 
   if native_window.is_fullscreen() {
+    // Native fullscreen always maps to per-monitor fullscreen with the
+    // configured defaults. Global (all-monitor) fullscreen is not entered
+    // here; a managed window reaches it only through
+    // `toggle_global_fullscreen`.
     return WindowState::Fullscreen(
       config.value.window_state_defaults.fullscreen.clone(),
     );
@@ -176,9 +380,8 @@ fn insertion_target(
 
   match focused_container.is_workspace() {
     true => Ok((focused_container, 0)),
-    false => Ok((
-      focused_container.parent().context("No insertion target.")?,
-      focused_container.index() + 1,
-    )),
+    // A new window lands immediately after the focused container, expressed
+    // through the same before/after primitive the move commands use.
+    false => insertion_index(&focused_container, Position::After),
   }
 }
@@ -0,0 +1,11 @@
+mod enforce_workspace_rules;
+mod manage_window;
+mod toggle_global_fullscreen;
+mod unmanage_window;
+mod update_window_state;
+
+pub use enforce_workspace_rules::*;
+pub use manage_window::*;
+pub use toggle_global_fullscreen::*;
+pub use unmanage_window::*;
+pub use update_window_state::*;
@@ -0,0 +1,153 @@
+use anyhow::Context;
+
+use crate::{
+  common::Rect,
+  containers::{traits::CommonGetters, Container, WindowContainer},
+  user_config::UserConfig,
+  windows::{
+    commands::update_window_state, traits::WindowGetters, WindowState,
+  },
+  wm_state::WmState,
+};
+
+/// Toggles a window into or out of global fullscreen.
+///
+/// Global fullscreen sizes the window to the union of every monitor's bounds
+/// and renders above all workspaces. Exiting restores the window's prior
+/// tiling/floating slot, reusing the same restore path as per-monitor
+/// fullscreen.
+pub fn toggle_global_fullscreen(
+  window: WindowContainer,
+  state: &mut WmState,
+  config: &UserConfig,
+) -> anyhow::Result<()> {
+  if window.state().is_global_fullscreen() {
+    // Restore the slot the window occupied before going fullscreen.
+    let restore_state =
+      window.prev_state().unwrap_or(WindowState::Tiling);
+    update_window_state(window, restore_state, state, config)?;
+  } else {
+    update_window_state(
+      window.clone(),
+      WindowState::GlobalFullscreen(
+        config.value.window_state_defaults.fullscreen.clone(),
+      ),
+      state,
+      config,
+    )?;
+
+    // Size the window to the union of all monitor bounds so it spans every
+    // display rather than a single workspace rect. Converting a tiling window
+    // replaces its container, so re-resolve it first.
+    let window = state
+      .window_from_native_handle(window.native().handle())
+      .context("Window vanished after entering global fullscreen.")?;
+    window.set_floating_placement(all_monitors_bounds(state)?);
+  }
+
+  // On entering, queue the workspaces the global window now covers so they
+  // repaint with the suppression state applied. On exit no workspace is
+  // hidden, so none are queued here; `update_window_state` has already queued
+  // every monitor for both transitions.
+  let workspaces = state.workspaces();
+  for workspace in workspaces {
+    if hidden_by_global_fullscreen(&workspace.clone().into(), state) {
+      state.add_container_to_redraw(workspace.into());
+    }
+  }
+
+  Ok(())
+}
+
+/// Whether `container`'s workspace is hidden underneath a globally-fullscreen
+/// window on another workspace.
+///
+/// The redraw path consults this to skip painting the contents of workspaces
+/// covered by a global-fullscreen window, which spans every monitor.
+pub fn hidden_by_global_fullscreen(
+  container: &Container,
+  state: &WmState,
+) -> bool {
+  let Some(global) = state.globally_fullscreen_window() else {
+    return false;
+  };
+
+  let global_workspace = global.parent_workspace();
+  match (container.parent_workspace(), global_workspace) {
+    (Some(workspace), Some(global_workspace)) => {
+      workspace.id() != global_workspace.id()
+    }
+    _ => false,
+  }
+}
+
+impl WmState {
+  /// Returns the window currently in global fullscreen, if any.
+  ///
+  /// The redraw path consults this to suppress drawing the contents of other
+  /// workspaces underneath a globally-fullscreen window, since it covers every
+  /// monitor.
+  pub fn globally_fullscreen_window(&self) -> Option<WindowContainer> {
+    self
+      .windows()
+      .into_iter()
+      .find(|window| window.state().is_global_fullscreen())
+  }
+}
+
+/// The smallest rect covering every monitor's bounds.
+fn all_monitors_bounds(state: &WmState) -> anyhow::Result<Rect> {
+  let rects = state
+    .monitors()
+    .into_iter()
+    .map(|monitor| monitor.to_rect())
+    .collect::<anyhow::Result<Vec<_>>>()?;
+
+  bounding_rect(&rects).context("No monitors.")
+}
+
+/// The smallest rect covering every rect in `rects`, or `None` when empty.
+fn bounding_rect(rects: &[Rect]) -> Option<Rect> {
+  let first = rects.first()?;
+
+  let mut left = first.left();
+  let mut top = first.top();
+  let mut right = first.right();
+  let mut bottom = first.bottom();
+
+  for rect in &rects[1..] {
+    left = left.min(rect.left());
+    top = top.min(rect.top());
+    right = right.max(rect.right());
+    bottom = bottom.max(rect.bottom());
+  }
+
+  Some(Rect::from_xy(left, top, right - left, bottom - top))
+}
+
+#[cfg(test)]
+mod tests {
+  use super::bounding_rect;
+  use crate::common::Rect;
+
+  #[test]
+  fn spans_the_union_of_all_monitors() {
+    let monitors = [
+      Rect::from_xy(0, 0, 1920, 1080),
+      Rect::from_xy(1920, 0, 1280, 1024),
+      Rect::from_xy(-1920, 200, 1920, 1080),
+    ];
+
+    let bounds = bounding_rect(&monitors).unwrap();
+
+    assert_eq!(bounds.left(), -1920);
+    assert_eq!(bounds.top(), 0);
+    assert_eq!(bounds.right(), 3200);
+    assert_eq!(bounds.bottom(), 1280);
+  }
+
+  #[test]
+  fn empty_has_no_bounds() {
+    assert!(bounding_rect(&[]).is_none());
+  }
+}
@@ -0,0 +1,31 @@
+use crate::{
+  containers::{
+    commands::detach_container, traits::CommonGetters, WindowContainer,
+  },
+  wm_state::WmState,
+};
+
+/// Stops managing a window, detaching it from the container tree.
+///
+/// This backs the `unmanage` window-rule command: a rule that matches a window
+/// the user never wants tiled runs it to drop the window from the WM. Since
+/// `manage_window` re-resolves the window by its native handle after running
+/// the rules and bails when the window is detached, unmanaging mid-rule leaves
+/// the rest of the manage path a no-op.
+pub fn unmanage_window(
+  window: WindowContainer,
+  state: &mut WmState,
+) -> anyhow::Result<()> {
+  let parent = window.parent();
+
+  detach_container(window.into())?;
+
+  if let Some(parent) = parent {
+    state.add_container_to_redraw(parent);
+  }
+
+  // Focus has to move off the now-unmanaged window on the next sync.
+  state.has_pending_focus_sync = true;
+
+  Ok(())
+}
@@ -7,10 +7,10 @@ use crate::{
       set_focused_descendant,
     },
     traits::CommonGetters,
-    WindowContainer,
+    Container, WindowContainer,
   },
   user_config::UserConfig,
-  windows::{traits::WindowGetters, WindowState},
+  windows::{traits::WindowGetters, NonTilingWindow, WindowState},
   wm_state::WmState,
 };
 
@@ -24,6 +24,19 @@ pub fn update_window_state(
     return Ok(());
   }
 
+  // Entering or leaving global fullscreen changes what is drawn on *every*
+  // monitor: a globally-fullscreen window spans the union of all monitor
+  // bounds and suppresses the contents of other workspaces underneath it. The
+  // per-slot restore still flows through `set_tiling`/`set_non_tiling` below,
+  // exactly like per-monitor fullscreen.
+  if window.state().is_global_fullscreen()
+    || window_state.is_global_fullscreen()
+  {
+    for monitor in state.monitors() {
+      state.add_container_to_redraw(monitor.into());
+    }
+  }
+
   match window_state {
     WindowState::Tiling => set_tiling(window, state, config),
     _ => set_non_tiling(window, window_state, state),
@@ -78,6 +91,26 @@ fn set_tiling(
   Ok(())
 }
 
+/// Prefers another floating sibling when a floating window is removed.
+///
+/// Returns the next floating sibling if there is one, otherwise the previous
+/// floating sibling, otherwise `None` so the caller can fall back to the
+/// workspace's generic focus order.
+fn floating_focus_fallback(window: &NonTilingWindow) -> Option<Container> {
+  let is_floating = |container: &Container| {
+    matches!(
+      container,
+      Container::NonTilingWindow(w)
+        if matches!(w.state(), WindowState::Floating(_))
+    )
+  };
+
+  window
+    .next_siblings()
+    .find(is_floating)
+    .or_else(|| window.prev_siblings().find(is_floating))
+}
+
 fn set_non_tiling(
   window: WindowContainer,
   window_state: WindowState,
@@ -85,7 +118,33 @@ fn set_non_tiling(
 ) -> anyhow::Result<()> {
   match window {
     WindowContainer::NonTilingWindow(window) => {
+      let minimizing = window_state == WindowState::Minimized;
+      let was_floating = matches!(window.state(), WindowState::Floating(_));
       window.set_state(window_state);
+
+      if minimizing {
+        state.unmanaged_or_minimized_timestamp =
+          Some(std::time::Instant::now());
+        state.has_pending_focus_sync = true;
+
+        // For a floating window, keep focus among the floats: prefer the next
+        // floating sibling, then the previous one, and only then fall back to
+        // the nearest descendant in the workspace's focus order so minimizing
+        // a float doesn't jump focus into the tiling tree.
+        let removed = window.clone().into();
+        let floating_target = if was_floating {
+          floating_focus_fallback(&window)
+        } else {
+          None
+        };
+        let focus_target = floating_target
+          .or_else(|| state.focus_target_after_removal(&removed));
+
+        if let Some(focus_target) = focus_target {
+          set_focused_descendant(focus_target, None);
+        }
+      }
+
       state.add_container_to_redraw(window.into());
     }
     WindowContainer::TilingWindow(window) => {
@@ -113,6 +172,8 @@ fn set_non_tiling(
           Some(std::time::Instant::now());
         state.has_pending_focus_sync = true;
 
+        // A minimized tiling window falls back to the workspace's generic
+        // focus order.
         if let Some(focus_target) =
           state.focus_target_after_removal(&non_tiling_window.into())
         {
@@ -0,0 +1,10 @@
+pub mod commands;
+pub mod traits;
+
+mod non_tiling_window;
+mod tiling_window;
+mod window_state;
+
+pub use non_tiling_window::*;
+pub use tiling_window::*;
+pub use window_state::*;
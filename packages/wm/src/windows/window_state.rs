@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+
+/// The layout state a managed window is currently in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum WindowState {
+  Tiling,
+  Floating(FloatingStateConfig),
+  Fullscreen(FullscreenStateConfig),
+  /// Global (all-monitor) fullscreen: the window spans the union of every
+  /// monitor's bounds and renders above all workspaces, as distinct from the
+  /// per-monitor [`WindowState::Fullscreen`].
+  GlobalFullscreen(FullscreenStateConfig),
+  Minimized,
+}
+
+impl WindowState {
+  /// Whether this state is global (all-monitor) fullscreen.
+  pub fn is_global_fullscreen(&self) -> bool {
+    matches!(self, WindowState::GlobalFullscreen(_))
+  }
+}
+
+/// Defaults applied to a window in the floating state.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FloatingStateConfig {
+  #[serde(default)]
+  pub centered: bool,
+  #[serde(default)]
+  pub shown_on_top: bool,
+}
+
+/// Defaults applied to a window in the fullscreen state.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FullscreenStateConfig {
+  #[serde(default)]
+  pub maximized: bool,
+  #[serde(default)]
+  pub remove_title_bar: bool,
+  #[serde(default)]
+  pub shown_on_top: bool,
+}